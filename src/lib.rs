@@ -2,10 +2,18 @@ pub mod adaptive;
 pub mod backoff;
 pub mod errors;
 
+#[cfg(feature = "tokio")]
+pub mod retry;
+
 pub mod prelude {
     pub use super::adaptive::{Adaptable, Adaptive, AdaptiveBuilder};
     pub use super::backoff::{
-        Backoff, BackoffBuilder, ExponentialBackoff, ExponentialBackoffBuilder,
+        Backoff, BackoffBuilder, ConstantBackoff, ConstantBackoffBuilder, ExponentialBackoff,
+        ExponentialBackoffBuilder, FibonacciBackoff, FibonacciBackoffBuilder, JitterMode,
+        LinearBackoff, LinearBackoffBuilder,
     };
     pub use super::errors::AdaptiveError;
+
+    #[cfg(feature = "tokio")]
+    pub use super::retry::{RetryAdaptiveExt, RetryError, RetryExt};
 }
@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 #[cfg(test)]
 use log::trace;
@@ -47,6 +47,19 @@ pub struct Adaptive<B: Backoff> {
 
     success_step: f64,
     fail_step: f64,
+
+    /// The maximum number of attempts the iterator will hand out before
+    /// yielding `None`. `wait()` itself is unaffected.
+    max_times: Option<usize>,
+    /// The number of attempts the iterator has handed out so far.
+    attempts: usize,
+
+    /// The total wall-clock budget allotted since the first `success()` or
+    /// `fail()` call.
+    max_elapsed_time: Option<Duration>,
+    /// When the budget started counting down, set on the first `success()`
+    /// or `fail()` call after construction or `reset()`.
+    start: Option<Instant>,
 }
 
 #[derive(Default)]
@@ -60,6 +73,8 @@ pub struct AdaptiveBuilder<'a, B: Backoff, BB: BackoffBuilder<B>> {
     success_factor: Option<f64>,
 
     initial_delay: Option<Duration>,
+    max_times: Option<usize>,
+    max_elapsed_time: Option<Duration>,
 }
 
 impl<'a, B: Backoff, BB: BackoffBuilder<B>> AdaptiveBuilder<'a, B, BB> {
@@ -114,13 +129,32 @@ impl<'a, B: Backoff, BB: BackoffBuilder<B>> AdaptiveBuilder<'a, B, BB> {
         self.initial_delay(d)
     }
 
+    /// sets the number of attempts the iterator will yield before stopping.
+    /// `wait()` called directly is unaffected.
+    pub fn max_times(&mut self, n: usize) -> &mut Self {
+        self.max_times = Some(n);
+        self
+    }
+
+    /// Alias for `max_times`.
+    pub fn max_attempts(&mut self, n: usize) -> &mut Self {
+        self.max_times(n)
+    }
+
+    /// The total wall-clock budget for retrying, starting from the first
+    /// `success()` or `fail()` call, regardless of how the delay grows.
+    pub fn max_elapsed_time(&mut self, d: Duration) -> &mut Self {
+        self.max_elapsed_time = Some(d);
+        self
+    }
+
     /// build returns the adaptive backoff.
-    pub fn build(self) -> Result<Adaptive<B>> {
+    pub fn build(&mut self) -> Result<Adaptive<B>> {
         let mut backoff = if self.backoff.is_some() && self.builder.is_some() {
             return Err(format_err!("adaptive builders from `.adaptive` on a backoff builder cannot be used with the `.backoff` function"));
-        } else if let Some(boff) = self.backoff {
+        } else if let Some(boff) = self.backoff.take() {
             boff
-        } else if let Some(boff) = self.builder {
+        } else if let Some(boff) = self.builder.take() {
             boff.build()?
         } else {
             return Err(format_err!("backoff for adaptive backoff builder not specified, must use `.adaptive` on a backoff builder or `.backoff()`"));
@@ -134,6 +168,19 @@ impl<'a, B: Backoff, BB: BackoffBuilder<B>> AdaptiveBuilder<'a, B, BB> {
         let fail_mult = self.fail_mult.unwrap_or(1.0);
         let success_mult = self.success_mult.unwrap_or(1.0);
 
+        if fail_mult <= 0.0 {
+            return Err(AdaptiveError::BuilderFailure {
+                msg: format!("fail_mult must be greater than 0, got {}", fail_mult),
+            }
+            .into());
+        }
+        if success_mult <= 0.0 {
+            return Err(AdaptiveError::BuilderFailure {
+                msg: format!("success_mult must be greater than 0, got {}", success_mult),
+            }
+            .into());
+        }
+
         Ok(Adaptive::<B> {
             backoff,
             fail_mult,
@@ -144,6 +191,10 @@ impl<'a, B: Backoff, BB: BackoffBuilder<B>> AdaptiveBuilder<'a, B, BB> {
             delay: Duration::from_secs_f64(0.0),
             success_step: 1.0 / success_mult,
             fail_step: 1.0 / fail_mult,
+            max_times: self.max_times,
+            attempts: 0,
+            max_elapsed_time: self.max_elapsed_time,
+            start: None,
         })
     }
 }
@@ -172,6 +223,49 @@ impl<B: Backoff> Backoff for Adaptive<B> {
         self.delay = self.base_delay;
         self.success_factor = 0.0;
         self.fail_factor = 0.0;
+        self.attempts = 0;
+        self.start = None;
+    }
+}
+
+impl<B: Backoff> Adaptive<B> {
+    /// Returns true once `max_elapsed_time` has been exceeded since the
+    /// first `success()` or `fail()` call. Always false if no budget was
+    /// configured or neither has been called yet.
+    pub fn is_expired(&self) -> bool {
+        match (self.max_elapsed_time, self.start) {
+            (Some(budget), Some(start)) => start.elapsed() > budget,
+            _ => false,
+        }
+    }
+
+    fn mark_used(&mut self) {
+        if self.max_elapsed_time.is_some() && self.start.is_none() {
+            self.start = Some(Instant::now());
+        }
+    }
+}
+
+/// Iterating an `Adaptive` yields successive `wait()` durations, stopping
+/// once `max_times` attempts have been handed out, or once
+/// `max_elapsed_time` has passed, so callers can `for delay in backoff { ... }`
+/// and give up instead of retrying forever.
+impl<B: Backoff> Iterator for Adaptive<B> {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        if let Some(max) = self.max_times {
+            if self.attempts >= max {
+                return None;
+            }
+        }
+
+        if self.is_expired() {
+            return None;
+        }
+
+        self.attempts += 1;
+        Some(self.wait())
     }
 }
 
@@ -179,6 +273,7 @@ impl<B: Backoff> Adaptable for Adaptive<B> {
     /// success resets the backoff, increases success factor by the success multiplier
     /// and reduces the new returned delay.
     fn success(&mut self) -> Duration {
+        self.mark_used();
         self.backoff.reset();
         self.success_factor += self.success_mult;
         match self
@@ -201,6 +296,7 @@ impl<B: Backoff> Adaptable for Adaptive<B> {
     /// fail uses the backoff and adds it, divided by the fail factor, to
     /// the running delay. It then returns the running delay.
     fn fail(&mut self) -> Duration {
+        self.mark_used();
         self.fail_factor += self.fail_mult;
         let delta = self.backoff.wait().div_f64(self.fail_factor);
         self.delay += delta;
@@ -260,3 +356,86 @@ fn test_adaptive_exp_backoff() {
         );
     }
 }
+
+#[test]
+fn test_adaptive_max_times() {
+    let mut backoff = ExponentialBackoffBuilder::default()
+        .min(Duration::from_secs_f64(0.0))
+        .max(Duration::from_secs_f64(100.0))
+        .factor(2.0)
+        .adaptive()
+        .max_times(3)
+        .build()
+        .unwrap();
+
+    let mut count = 0;
+    while backoff.next().is_some() {
+        count += 1;
+    }
+    assert_eq!(3, count);
+    assert!(backoff.next().is_none(), "iterator should stay exhausted");
+
+    backoff.reset();
+
+    let mut count = 0;
+    while backoff.next().is_some() {
+        count += 1;
+    }
+    assert_eq!(3, count, "reset should let the iterator run again");
+}
+
+#[test]
+fn test_adaptive_max_elapsed_time() {
+    let mut backoff = ExponentialBackoffBuilder::default()
+        .min(Duration::from_secs_f64(0.0))
+        .max(Duration::from_secs_f64(100.0))
+        .factor(2.0)
+        .adaptive()
+        .max_elapsed_time(Duration::from_millis(10))
+        .build()
+        .unwrap();
+
+    assert!(!backoff.is_expired(), "budget hasn't started yet");
+
+    backoff.fail();
+    assert!(!backoff.is_expired(), "budget just started");
+
+    std::thread::sleep(Duration::from_millis(30));
+    assert!(backoff.is_expired());
+    assert!(
+        backoff.next().is_none(),
+        "iterator should stop once the budget is exceeded"
+    );
+
+    backoff.reset();
+    assert!(!backoff.is_expired(), "reset should clear the budget");
+}
+
+#[test]
+fn test_adaptive_build_validation() {
+    let err = ExponentialBackoffBuilder::default()
+        .min(Duration::from_secs_f64(0.0))
+        .max(Duration::from_secs_f64(100.0))
+        .factor(2.0)
+        .adaptive()
+        .fail_mult(0.0)
+        .build();
+    assert!(err.is_err(), "fail_mult <= 0 should be rejected");
+
+    let err = ExponentialBackoffBuilder::default()
+        .min(Duration::from_secs_f64(0.0))
+        .max(Duration::from_secs_f64(100.0))
+        .factor(2.0)
+        .adaptive()
+        .success_mult(-1.0)
+        .build();
+    assert!(err.is_err(), "success_mult <= 0 should be rejected");
+
+    let ok = ExponentialBackoffBuilder::default()
+        .min(Duration::from_secs_f64(0.0))
+        .max(Duration::from_secs_f64(100.0))
+        .factor(2.0)
+        .adaptive()
+        .build();
+    assert!(ok.is_ok(), "valid input should still build");
+}
@@ -1,4 +1,6 @@
-use std::time::Duration;
+use std::time::{Duration, Instant};
+
+use rand::Rng;
 
 use super::errors::*;
 
@@ -14,6 +16,27 @@ pub trait Backoff {
     fn reset(&mut self);
 }
 
+/// JitterMode randomizes the delay returned by `wait()` so that many clients
+/// backing off at once don't stay synchronized on the same schedule.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum JitterMode {
+    /// No randomization. The default.
+    #[default]
+    None,
+
+    /// Returns a value picked uniformly between 0 and the computed delay.
+    Full,
+
+    /// Returns half the computed delay plus a value picked uniformly between
+    /// 0 and the other half.
+    Equal,
+
+    /// Ignores the normal exponential formula entirely. Each call picks a
+    /// value uniformly between `min` and 3x the previous delay, capped at
+    /// `max`, and carries that value forward as the new previous delay.
+    Decorrelated,
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct ExponentialBackoff {
     factor: f64,
@@ -21,10 +44,40 @@ pub struct ExponentialBackoff {
     min: f64,
 
     hits: i32,
+
+    jitter: JitterMode,
+    /// The previous delay returned, used as the basis for decorrelated jitter.
+    prev: f64,
+
+    /// The maximum number of attempts the iterator will hand out before
+    /// yielding `None`. `wait()` itself is unaffected and keeps running.
+    max_times: Option<usize>,
+
+    /// The total wall-clock budget allotted since the first `wait()` call.
+    max_elapsed_time: Option<Duration>,
+    /// When the budget started counting down, set on the first `wait()`
+    /// call after construction or `reset()`.
+    start: Option<Instant>,
 }
 
 impl Backoff for ExponentialBackoff {
     fn wait(&mut self) -> Duration {
+        if self.max_elapsed_time.is_some() && self.start.is_none() {
+            self.start = Some(Instant::now());
+        }
+
+        if self.jitter == JitterMode::Decorrelated {
+            let hi = (self.prev * 3.0).max(self.min);
+            let mut secs = rand::thread_rng().gen_range(self.min..=hi);
+            if let Some(max) = self.max {
+                secs = secs.min(max);
+            }
+            self.hits += 1;
+            self.prev = secs;
+
+            return Duration::from_secs_f64(secs);
+        }
+
         let mut secs = self.factor.powi(self.hits);
         self.hits += 1;
         secs = secs.max(self.min);
@@ -32,11 +85,56 @@ impl Backoff for ExponentialBackoff {
             secs = secs.min(max)
         }
 
+        secs = match self.jitter {
+            JitterMode::Full => rand::thread_rng().gen_range(0.0..=secs),
+            JitterMode::Equal => {
+                let half = secs / 2.0;
+                half + rand::thread_rng().gen_range(0.0..=half)
+            }
+            _ => secs,
+        };
+
         Duration::from_secs_f64(secs)
     }
 
     fn reset(&mut self) {
         self.hits = 1;
+        self.prev = self.min;
+        self.start = None;
+    }
+}
+
+impl ExponentialBackoff {
+    /// Returns true once `max_elapsed_time` has been exceeded since the
+    /// first `wait()` call. Always false if no budget was configured or
+    /// `wait()` hasn't been called yet.
+    pub fn is_expired(&self) -> bool {
+        match (self.max_elapsed_time, self.start) {
+            (Some(budget), Some(start)) => start.elapsed() > budget,
+            _ => false,
+        }
+    }
+}
+
+/// Iterating an `ExponentialBackoff` yields successive `wait()` durations,
+/// stopping once `max_times` attempts have been handed out, or once
+/// `max_elapsed_time` has passed, so callers can `for delay in backoff { ... }`
+/// and give up instead of retrying forever.
+impl Iterator for ExponentialBackoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        if let Some(max) = self.max_times {
+            if self.hits as usize > max {
+                return None;
+            }
+        }
+
+        if self.is_expired() {
+            return None;
+        }
+
+        Some(self.wait())
     }
 }
 
@@ -49,16 +147,75 @@ pub struct ExponentialBackoffBuilder {
     min: Option<Duration>,
     max: Option<Duration>,
     factor: Option<f64>,
+    jitter: Option<JitterMode>,
+    max_times: Option<usize>,
+    max_elapsed_time: Option<Duration>,
+}
+
+/// Checks the invariants `ExponentialBackoff` relies on, shared by
+/// `ExponentialBackoffBuilder::build` and `ExponentialBackoff::try_new` so
+/// both paths reject the same bad input.
+fn validate_exponential(min: f64, max: Option<f64>, factor: f64) -> Result<()> {
+    if factor <= 0.0 {
+        return Err(AdaptiveError::BuilderFailure {
+            msg: format!("factor must be greater than 0, got {}", factor),
+        }
+        .into());
+    }
+    if let Some(max) = max {
+        if min > max {
+            return Err(AdaptiveError::BuilderFailure {
+                msg: format!("min ({}) must not be greater than max ({})", min, max),
+            }
+            .into());
+        }
+    }
+
+    Ok(())
 }
 
 impl BackoffBuilder<ExponentialBackoff> for ExponentialBackoffBuilder {
     /// Build finishes the exponential backoff and returns it or an error.
     fn build(&mut self) -> Result<ExponentialBackoff> {
+        let min = self
+            .min
+            .ok_or_else(|| format_err!("the minimum initial value is required"))?
+            .as_secs_f64();
+        let max = self.max.map(|s| s.as_secs_f64());
+        let factor = self.factor.unwrap_or(2.0);
+
+        validate_exponential(min, max, factor)?;
+
         Ok(ExponentialBackoff {
-            min: self.min.ok_or_else(|| format_err!("the minimum initial value is required"))?.as_secs_f64(),
-            max: self.max.map(|s| s.as_secs_f64()),
+            min,
+            max,
+            hits: 1,
+            factor,
+            jitter: self.jitter.unwrap_or_default(),
+            prev: min,
+            max_times: self.max_times,
+            max_elapsed_time: self.max_elapsed_time,
+            ..ExponentialBackoff::default()
+        })
+    }
+}
+
+impl ExponentialBackoff {
+    /// Constructs an `ExponentialBackoff` directly, without going through
+    /// `ExponentialBackoffBuilder`, applying the same validation `build()`
+    /// does instead of surfacing bad input as a later runtime surprise.
+    pub fn try_new(min: Duration, max: Option<Duration>, factor: f64) -> Result<Self> {
+        let min = min.as_secs_f64();
+        let max = max.map(|s| s.as_secs_f64());
+
+        validate_exponential(min, max, factor)?;
+
+        Ok(ExponentialBackoff {
+            min,
+            max,
             hits: 1,
-            factor: self.factor.unwrap_or(2.0),
+            factor,
+            prev: min,
             ..ExponentialBackoff::default()
         })
     }
@@ -82,6 +239,321 @@ impl ExponentialBackoffBuilder {
         self.factor = Some(f);
         self
     }
+
+    /// The jitter strategy applied to each returned delay. Defaults to
+    /// `JitterMode::None`, which is fully deterministic.
+    pub fn jitter(&mut self, jitter: JitterMode) -> &mut Self {
+        self.jitter = Some(jitter);
+        self
+    }
+
+    /// The number of attempts the backoff's iterator will yield before
+    /// stopping. `wait()` called directly is unaffected.
+    pub fn max_times(&mut self, n: usize) -> &mut Self {
+        self.max_times = Some(n);
+        self
+    }
+
+    /// Alias for `max_times`.
+    pub fn max_attempts(&mut self, n: usize) -> &mut Self {
+        self.max_times(n)
+    }
+
+    /// The total wall-clock budget for retrying, starting from the first
+    /// `wait()` call, regardless of how the per-attempt delay grows.
+    pub fn max_elapsed_time(&mut self, d: Duration) -> &mut Self {
+        self.max_elapsed_time = Some(d);
+        self
+    }
+}
+
+/// ConstantBackoff always returns the same fixed delay.
+#[derive(Debug, Default, Clone)]
+pub struct ConstantBackoff {
+    delay: f64,
+
+    hits: usize,
+    /// The maximum number of attempts the iterator will hand out before
+    /// yielding `None`. `wait()` itself is unaffected and keeps running.
+    max_times: Option<usize>,
+}
+
+impl Backoff for ConstantBackoff {
+    fn wait(&mut self) -> Duration {
+        self.hits += 1;
+        Duration::from_secs_f64(self.delay)
+    }
+
+    fn reset(&mut self) {
+        self.hits = 0;
+    }
+}
+
+/// Iterating a `ConstantBackoff` yields successive `wait()` durations,
+/// stopping once `max_times` attempts have been handed out so callers can
+/// `for delay in backoff { ... }` and give up instead of retrying forever.
+impl Iterator for ConstantBackoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        if let Some(max) = self.max_times {
+            if self.hits >= max {
+                return None;
+            }
+        }
+
+        Some(self.wait())
+    }
+}
+
+#[derive(Default, Debug)]
+pub struct ConstantBackoffBuilder {
+    delay: Option<Duration>,
+    max_times: Option<usize>,
+}
+
+impl BackoffBuilder<ConstantBackoff> for ConstantBackoffBuilder {
+    /// Build finishes the constant backoff and returns it or an error.
+    fn build(&mut self) -> Result<ConstantBackoff> {
+        Ok(ConstantBackoff {
+            delay: self
+                .delay
+                .ok_or_else(|| format_err!("the delay is required"))?
+                .as_secs_f64(),
+            hits: 0,
+            max_times: self.max_times,
+        })
+    }
+}
+
+impl ConstantBackoffBuilder {
+    /// The fixed delay returned on every call to `wait()`.
+    pub fn delay(&mut self, delay: Duration) -> &mut Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// The number of attempts the backoff's iterator will yield before
+    /// stopping. `wait()` called directly is unaffected.
+    pub fn max_times(&mut self, n: usize) -> &mut Self {
+        self.max_times = Some(n);
+        self
+    }
+
+    /// Alias for `max_times`.
+    pub fn max_attempts(&mut self, n: usize) -> &mut Self {
+        self.max_times(n)
+    }
+}
+
+/// LinearBackoff grows the delay by a fixed `step` on every hit, capped at
+/// `max`.
+#[derive(Debug, Default, Clone)]
+pub struct LinearBackoff {
+    min: f64,
+    max: Option<f64>,
+    step: f64,
+
+    hits: i32,
+    /// The maximum number of attempts the iterator will hand out before
+    /// yielding `None`. `wait()` itself is unaffected and keeps running.
+    max_times: Option<usize>,
+}
+
+impl Backoff for LinearBackoff {
+    fn wait(&mut self) -> Duration {
+        let mut secs = self.min + self.step * self.hits as f64;
+        self.hits += 1;
+        if let Some(max) = self.max {
+            secs = secs.min(max);
+        }
+
+        Duration::from_secs_f64(secs)
+    }
+
+    fn reset(&mut self) {
+        self.hits = 0;
+    }
+}
+
+/// Iterating a `LinearBackoff` yields successive `wait()` durations,
+/// stopping once `max_times` attempts have been handed out so callers can
+/// `for delay in backoff { ... }` and give up instead of retrying forever.
+impl Iterator for LinearBackoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        if let Some(max) = self.max_times {
+            if self.hits as usize >= max {
+                return None;
+            }
+        }
+
+        Some(self.wait())
+    }
+}
+
+#[derive(Default, Debug)]
+pub struct LinearBackoffBuilder {
+    min: Option<Duration>,
+    max: Option<Duration>,
+    step: Option<Duration>,
+    max_times: Option<usize>,
+}
+
+impl BackoffBuilder<LinearBackoff> for LinearBackoffBuilder {
+    /// Build finishes the linear backoff and returns it or an error.
+    fn build(&mut self) -> Result<LinearBackoff> {
+        Ok(LinearBackoff {
+            min: self
+                .min
+                .ok_or_else(|| format_err!("the minimum initial value is required"))?
+                .as_secs_f64(),
+            max: self.max.map(|s| s.as_secs_f64()),
+            step: self.step.unwrap_or(Duration::from_secs_f64(1.0)).as_secs_f64(),
+            hits: 0,
+            max_times: self.max_times,
+        })
+    }
+}
+
+impl LinearBackoffBuilder {
+    /// The minimum and initial delay of the backoff.
+    pub fn min(&mut self, min: Duration) -> &mut Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// The capped maximum delay that can be returned.
+    pub fn max(&mut self, max: Duration) -> &mut Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// The amount added to the delay on each hit. Defaults to one second.
+    pub fn step(&mut self, step: Duration) -> &mut Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// The number of attempts the backoff's iterator will yield before
+    /// stopping. `wait()` called directly is unaffected.
+    pub fn max_times(&mut self, n: usize) -> &mut Self {
+        self.max_times = Some(n);
+        self
+    }
+
+    /// Alias for `max_times`.
+    pub fn max_attempts(&mut self, n: usize) -> &mut Self {
+        self.max_times(n)
+    }
+}
+
+/// FibonacciBackoff scales the delay by successive Fibonacci numbers times a
+/// `base` unit, capped at `max`.
+#[derive(Debug, Default, Clone)]
+pub struct FibonacciBackoff {
+    base: f64,
+    max: Option<f64>,
+
+    /// The running Fibonacci pair; `wait()` returns `base * a` and advances
+    /// both terms.
+    a: f64,
+    b: f64,
+
+    hits: usize,
+    /// The maximum number of attempts the iterator will hand out before
+    /// yielding `None`. `wait()` itself is unaffected and keeps running.
+    max_times: Option<usize>,
+}
+
+impl Backoff for FibonacciBackoff {
+    fn wait(&mut self) -> Duration {
+        let mut secs = self.base * self.a;
+        if let Some(max) = self.max {
+            secs = secs.min(max);
+        }
+
+        let next = self.a + self.b;
+        self.a = self.b;
+        self.b = next;
+        self.hits += 1;
+
+        Duration::from_secs_f64(secs)
+    }
+
+    fn reset(&mut self) {
+        self.a = 1.0;
+        self.b = 1.0;
+        self.hits = 0;
+    }
+}
+
+/// Iterating a `FibonacciBackoff` yields successive `wait()` durations,
+/// stopping once `max_times` attempts have been handed out so callers can
+/// `for delay in backoff { ... }` and give up instead of retrying forever.
+impl Iterator for FibonacciBackoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        if let Some(max) = self.max_times {
+            if self.hits >= max {
+                return None;
+            }
+        }
+
+        Some(self.wait())
+    }
+}
+
+#[derive(Default, Debug)]
+pub struct FibonacciBackoffBuilder {
+    base: Option<Duration>,
+    max: Option<Duration>,
+    max_times: Option<usize>,
+}
+
+impl BackoffBuilder<FibonacciBackoff> for FibonacciBackoffBuilder {
+    /// Build finishes the Fibonacci backoff and returns it or an error.
+    fn build(&mut self) -> Result<FibonacciBackoff> {
+        Ok(FibonacciBackoff {
+            base: self
+                .base
+                .ok_or_else(|| format_err!("the base delay unit is required"))?
+                .as_secs_f64(),
+            max: self.max.map(|s| s.as_secs_f64()),
+            a: 1.0,
+            b: 1.0,
+            hits: 0,
+            max_times: self.max_times,
+        })
+    }
+}
+
+impl FibonacciBackoffBuilder {
+    /// The base delay unit that each Fibonacci term is multiplied by.
+    pub fn base(&mut self, base: Duration) -> &mut Self {
+        self.base = Some(base);
+        self
+    }
+
+    /// The capped maximum delay that can be returned.
+    pub fn max(&mut self, max: Duration) -> &mut Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// The number of attempts the backoff's iterator will yield before
+    /// stopping. `wait()` called directly is unaffected.
+    pub fn max_times(&mut self, n: usize) -> &mut Self {
+        self.max_times = Some(n);
+        self
+    }
+
+    /// Alias for `max_times`.
+    pub fn max_attempts(&mut self, n: usize) -> &mut Self {
+        self.max_times(n)
+    }
 }
 
 #[test]
@@ -112,3 +584,351 @@ fn test_exp_backoff() {
         delay
     );
 }
+
+#[test]
+fn test_full_jitter_backoff() {
+    let mut jittered = ExponentialBackoffBuilder::default()
+        .min(Duration::from_secs_f64(1.0))
+        .max(Duration::from_secs_f64(100.0))
+        .factor(2.0)
+        .jitter(JitterMode::Full)
+        .build()
+        .unwrap();
+    let mut plain = ExponentialBackoffBuilder::default()
+        .min(Duration::from_secs_f64(1.0))
+        .max(Duration::from_secs_f64(100.0))
+        .factor(2.0)
+        .build()
+        .unwrap();
+
+    for i in 0..20 {
+        let computed = plain.wait();
+        let delay = jittered.wait();
+
+        assert!(
+            delay <= computed,
+            "on iter {}: {:?} should be <= computed {:?}",
+            i,
+            delay,
+            computed
+        );
+    }
+}
+
+#[test]
+fn test_equal_jitter_backoff() {
+    let mut jittered = ExponentialBackoffBuilder::default()
+        .min(Duration::from_secs_f64(1.0))
+        .max(Duration::from_secs_f64(100.0))
+        .factor(2.0)
+        .jitter(JitterMode::Equal)
+        .build()
+        .unwrap();
+    let mut plain = ExponentialBackoffBuilder::default()
+        .min(Duration::from_secs_f64(1.0))
+        .max(Duration::from_secs_f64(100.0))
+        .factor(2.0)
+        .build()
+        .unwrap();
+
+    for i in 0..20 {
+        let computed = plain.wait();
+        let half = computed.div_f64(2.0);
+        let delay = jittered.wait();
+
+        assert!(
+            delay >= half && delay <= computed,
+            "on iter {}: {:?} should be within [{:?}, {:?}]",
+            i,
+            delay,
+            half,
+            computed
+        );
+    }
+}
+
+#[test]
+fn test_decorrelated_jitter_backoff() {
+    let min = Duration::from_secs_f64(1.0);
+    let max = Duration::from_secs_f64(100.0);
+    let mut backoff = ExponentialBackoffBuilder::default()
+        .min(min)
+        .max(max)
+        .factor(2.0)
+        .jitter(JitterMode::Decorrelated)
+        .build()
+        .unwrap();
+
+    let mut prev = min;
+    for i in 0..20 {
+        let delay = backoff.wait();
+        let hi = prev.mul_f64(3.0).max(min).min(max);
+
+        assert!(
+            delay >= min && delay <= hi,
+            "on iter {}: {:?} should be within [{:?}, {:?}]",
+            i,
+            delay,
+            min,
+            hi
+        );
+        prev = delay;
+    }
+
+    backoff.reset();
+    let delay = backoff.wait();
+    assert!(
+        delay >= min && delay <= min.mul_f64(3.0).max(min).min(max),
+        "after reset: {:?} should restart from min",
+        delay
+    );
+}
+
+#[test]
+fn test_constant_backoff() {
+    let mut backoff = ConstantBackoffBuilder::default()
+        .delay(Duration::from_secs(5))
+        .build()
+        .unwrap();
+
+    for _ in 0..5 {
+        assert_eq!(Duration::from_secs(5), backoff.wait());
+    }
+
+    backoff.reset();
+    assert_eq!(Duration::from_secs(5), backoff.wait());
+}
+
+#[test]
+fn test_constant_backoff_max_times() {
+    let mut backoff = ConstantBackoffBuilder::default()
+        .delay(Duration::from_secs(5))
+        .max_times(3)
+        .build()
+        .unwrap();
+
+    let mut count = 0;
+    while backoff.next().is_some() {
+        count += 1;
+    }
+    assert_eq!(3, count);
+    assert!(backoff.next().is_none(), "iterator should stay exhausted");
+
+    backoff.reset();
+
+    let mut count = 0;
+    while backoff.next().is_some() {
+        count += 1;
+    }
+    assert_eq!(3, count, "reset should let the iterator run again");
+}
+
+#[test]
+fn test_linear_backoff() {
+    let mut backoff = LinearBackoffBuilder::default()
+        .min(Duration::from_secs(1))
+        .max(Duration::from_secs(5))
+        .step(Duration::from_secs(1))
+        .build()
+        .unwrap();
+
+    let expected = [1, 2, 3, 4, 5, 5, 5];
+    for (i, exp) in expected.iter().enumerate() {
+        let delay = backoff.wait();
+        assert_eq!(
+            Duration::from_secs(*exp),
+            delay,
+            "on iter {}: {:?} != {:?}",
+            i,
+            exp,
+            delay
+        );
+    }
+
+    backoff.reset();
+    assert_eq!(Duration::from_secs(1), backoff.wait());
+}
+
+#[test]
+fn test_linear_backoff_max_times() {
+    let mut backoff = LinearBackoffBuilder::default()
+        .min(Duration::from_secs(1))
+        .max(Duration::from_secs(5))
+        .step(Duration::from_secs(1))
+        .max_times(3)
+        .build()
+        .unwrap();
+
+    let mut count = 0;
+    while backoff.next().is_some() {
+        count += 1;
+    }
+    assert_eq!(3, count);
+    assert!(backoff.next().is_none(), "iterator should stay exhausted");
+
+    backoff.reset();
+
+    let mut count = 0;
+    while backoff.next().is_some() {
+        count += 1;
+    }
+    assert_eq!(3, count, "reset should let the iterator run again");
+}
+
+#[test]
+fn test_exp_backoff_max_times() {
+    let mut backoff = ExponentialBackoffBuilder::default()
+        .min(Duration::from_secs_f64(0.0))
+        .max(Duration::from_secs_f64(100.0))
+        .factor(2.0)
+        .max_times(3)
+        .build()
+        .unwrap();
+
+    let mut count = 0;
+    while backoff.next().is_some() {
+        count += 1;
+    }
+    assert_eq!(3, count);
+    assert!(backoff.next().is_none(), "iterator should stay exhausted");
+
+    backoff.reset();
+
+    let mut count = 0;
+    while backoff.next().is_some() {
+        count += 1;
+    }
+    assert_eq!(3, count, "reset should let the iterator run again");
+}
+
+#[test]
+fn test_exp_backoff_max_elapsed_time() {
+    let mut backoff = ExponentialBackoffBuilder::default()
+        .min(Duration::from_secs_f64(0.0))
+        .max(Duration::from_secs_f64(100.0))
+        .factor(2.0)
+        .max_elapsed_time(Duration::from_millis(10))
+        .build()
+        .unwrap();
+
+    assert!(!backoff.is_expired(), "budget hasn't started yet");
+
+    backoff.wait();
+    assert!(!backoff.is_expired(), "budget just started");
+
+    std::thread::sleep(Duration::from_millis(30));
+    assert!(backoff.is_expired());
+    assert!(
+        backoff.next().is_none(),
+        "iterator should stop once the budget is exceeded"
+    );
+
+    backoff.reset();
+    assert!(!backoff.is_expired(), "reset should clear the budget");
+}
+
+#[test]
+fn test_exp_backoff_build_validation() {
+    let err = ExponentialBackoffBuilder::default()
+        .min(Duration::from_secs_f64(1.0))
+        .max(Duration::from_secs_f64(100.0))
+        .factor(0.0)
+        .build();
+    assert!(err.is_err(), "factor <= 0 should be rejected");
+
+    let err = ExponentialBackoffBuilder::default()
+        .min(Duration::from_secs_f64(100.0))
+        .max(Duration::from_secs_f64(1.0))
+        .factor(2.0)
+        .build();
+    assert!(err.is_err(), "min > max should be rejected");
+
+    let ok = ExponentialBackoffBuilder::default()
+        .min(Duration::from_secs_f64(1.0))
+        .max(Duration::from_secs_f64(100.0))
+        .factor(2.0)
+        .build();
+    assert!(ok.is_ok(), "valid input should still build");
+}
+
+#[test]
+fn test_exp_backoff_try_new() {
+    let ok = ExponentialBackoff::try_new(
+        Duration::from_secs_f64(1.0),
+        Some(Duration::from_secs_f64(100.0)),
+        2.0,
+    );
+    assert!(ok.is_ok(), "valid input should build");
+
+    let err = ExponentialBackoff::try_new(Duration::from_secs_f64(1.0), None, 0.0);
+    assert!(
+        matches!(
+            err.unwrap_err().downcast_ref::<AdaptiveError>(),
+            Some(AdaptiveError::BuilderFailure { .. })
+        ),
+        "factor <= 0 should surface a BuilderFailure"
+    );
+
+    let err = ExponentialBackoff::try_new(
+        Duration::from_secs_f64(100.0),
+        Some(Duration::from_secs_f64(1.0)),
+        2.0,
+    );
+    assert!(
+        matches!(
+            err.unwrap_err().downcast_ref::<AdaptiveError>(),
+            Some(AdaptiveError::BuilderFailure { .. })
+        ),
+        "min > max should surface a BuilderFailure"
+    );
+}
+
+#[test]
+fn test_fibonacci_backoff() {
+    let mut backoff = FibonacciBackoffBuilder::default()
+        .base(Duration::from_secs(1))
+        .max(Duration::from_secs(10))
+        .build()
+        .unwrap();
+
+    let expected = [1, 1, 2, 3, 5, 8, 10, 10];
+    for (i, exp) in expected.iter().enumerate() {
+        let delay = backoff.wait();
+        assert_eq!(
+            Duration::from_secs(*exp),
+            delay,
+            "on iter {}: {:?} != {:?}",
+            i,
+            exp,
+            delay
+        );
+    }
+
+    backoff.reset();
+    assert_eq!(Duration::from_secs(1), backoff.wait());
+}
+
+#[test]
+fn test_fibonacci_backoff_max_times() {
+    let mut backoff = FibonacciBackoffBuilder::default()
+        .base(Duration::from_secs(1))
+        .max(Duration::from_secs(10))
+        .max_times(3)
+        .build()
+        .unwrap();
+
+    let mut count = 0;
+    while backoff.next().is_some() {
+        count += 1;
+    }
+    assert_eq!(3, count);
+    assert!(backoff.next().is_none(), "iterator should stay exhausted");
+
+    backoff.reset();
+
+    let mut count = 0;
+    while backoff.next().is_some() {
+        count += 1;
+    }
+    assert_eq!(3, count, "reset should let the iterator run again");
+}
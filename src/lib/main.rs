@@ -4,6 +4,10 @@ pub mod errors;
 
 pub mod prelude {
     pub use super::adaptive::{Adaptable, Adaptive, AdaptiveBuilder};
-    pub use super::backoff::{Backoff, ExponentialBackoff, ExponentialBackoffBuilder};
+    pub use super::backoff::{
+        Backoff, ConstantBackoff, ConstantBackoffBuilder, ExponentialBackoff,
+        ExponentialBackoffBuilder, FibonacciBackoff, FibonacciBackoffBuilder, LinearBackoff,
+        LinearBackoffBuilder,
+    };
     pub use super::errors::{AdaptiveError};
 }
@@ -0,0 +1,358 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use pin_project::pin_project;
+use tokio::time::Sleep;
+
+use super::adaptive::{Adaptable, Adaptive};
+use super::backoff::Backoff;
+
+/// The outcome of a single retry attempt. A `Transient` error consumes the
+/// next backoff delay and is retried; a `Permanent` error is returned to the
+/// caller immediately with no further backoff.
+#[derive(Debug)]
+pub enum RetryError<E> {
+    Transient(E),
+    Permanent(E),
+}
+
+/// Extension trait adding `.retry(&mut backoff)` to any `FnMut() -> Fut`
+/// where `Fut` resolves to a `Result<T, RetryError<E>>`. The returned future
+/// drives repeated attempts against a `Backoff`, sleeping for `backoff`'s
+/// next delay between transient failures, until the operation succeeds,
+/// fails permanently, or the backoff's iterator is exhausted.
+///
+/// On success this only calls the generic `Backoff::reset()`. For
+/// `Adaptive<B>` that throws away the gradual delay decay `Adaptable`
+/// provides; use [`RetryAdaptiveExt::retry_adaptive`] instead when retrying
+/// against an `Adaptive<B>`.
+pub trait RetryExt<T, E, Fut>: FnMut() -> Fut + Sized
+where
+    Fut: Future<Output = Result<T, RetryError<E>>>,
+{
+    fn retry<B: Backoff + Iterator<Item = Duration>>(
+        self,
+        backoff: &mut B,
+    ) -> Retrying<'_, Self, B, Fut> {
+        Retrying {
+            op: self,
+            backoff,
+            state: State::Idle,
+        }
+    }
+}
+
+impl<T, E, Fut, F> RetryExt<T, E, Fut> for F
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, RetryError<E>>>,
+{
+}
+
+#[pin_project(project = StateProj)]
+enum State<Fut> {
+    Idle,
+    Attempt(#[pin] Fut),
+    Sleep(#[pin] Sleep),
+}
+
+/// The future returned by [`RetryExt::retry`].
+#[pin_project]
+pub struct Retrying<'b, F, B, Fut> {
+    op: F,
+    backoff: &'b mut B,
+    #[pin]
+    state: State<Fut>,
+}
+
+impl<'b, T, E, Fut, F, B> Future for Retrying<'b, F, B, Fut>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, RetryError<E>>>,
+    B: Backoff + Iterator<Item = Duration>,
+{
+    type Output = Result<T, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            match this.state.as_mut().project() {
+                StateProj::Idle => {
+                    let fut = (this.op)();
+                    this.state.set(State::Attempt(fut));
+                }
+                StateProj::Attempt(fut) => match fut.poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Ok(value)) => {
+                        this.backoff.reset();
+                        return Poll::Ready(Ok(value));
+                    }
+                    Poll::Ready(Err(RetryError::Permanent(e))) => {
+                        return Poll::Ready(Err(e));
+                    }
+                    Poll::Ready(Err(RetryError::Transient(e))) => match this.backoff.next() {
+                        Some(delay) => this.state.set(State::Sleep(tokio::time::sleep(delay))),
+                        None => return Poll::Ready(Err(e)),
+                    },
+                },
+                StateProj::Sleep(sleep) => match sleep.poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(()) => this.state.set(State::Idle),
+                },
+            }
+        }
+    }
+}
+
+/// Extension trait mirroring [`RetryExt`], specialized for `Adaptive<B>`. It
+/// calls `Adaptable::success()` on success and `Adaptable::fail()` on a
+/// transient error instead of `Backoff::reset()` and the `Iterator`'s
+/// delay; `Iterator::next()` is still called on each transient error, only
+/// to apply `max_times`/`max_elapsed_time`. The adaptive delay therefore
+/// decays gradually across attempts instead of being reset to its base
+/// value every time the operation succeeds.
+pub trait RetryAdaptiveExt<T, E, Fut>: FnMut() -> Fut + Sized
+where
+    Fut: Future<Output = Result<T, RetryError<E>>>,
+{
+    fn retry_adaptive<B: Backoff>(
+        self,
+        backoff: &mut Adaptive<B>,
+    ) -> RetryingAdaptive<'_, Self, B, Fut> {
+        RetryingAdaptive {
+            op: self,
+            backoff,
+            state: State::Idle,
+        }
+    }
+}
+
+impl<T, E, Fut, F> RetryAdaptiveExt<T, E, Fut> for F
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, RetryError<E>>>,
+{
+}
+
+/// The future returned by [`RetryAdaptiveExt::retry_adaptive`].
+#[pin_project]
+pub struct RetryingAdaptive<'b, F, B: Backoff, Fut> {
+    op: F,
+    backoff: &'b mut Adaptive<B>,
+    #[pin]
+    state: State<Fut>,
+}
+
+impl<'b, T, E, Fut, F, B> Future for RetryingAdaptive<'b, F, B, Fut>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, RetryError<E>>>,
+    B: Backoff,
+{
+    type Output = Result<T, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            match this.state.as_mut().project() {
+                StateProj::Idle => {
+                    let fut = (this.op)();
+                    this.state.set(State::Attempt(fut));
+                }
+                StateProj::Attempt(fut) => match fut.poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Ok(value)) => {
+                        this.backoff.success();
+                        return Poll::Ready(Ok(value));
+                    }
+                    Poll::Ready(Err(RetryError::Permanent(e))) => {
+                        return Poll::Ready(Err(e));
+                    }
+                    Poll::Ready(Err(RetryError::Transient(e))) => {
+                        // `Adaptive<B>`'s Iterator impl is reused purely to
+                        // apply `max_times`/`max_elapsed_time`; the delay
+                        // itself comes from `fail()` so the decay state
+                        // advances correctly.
+                        if this.backoff.next().is_none() {
+                            return Poll::Ready(Err(e));
+                        }
+                        let delay = this.backoff.fail();
+                        this.state.set(State::Sleep(tokio::time::sleep(delay)));
+                    }
+                },
+                StateProj::Sleep(sleep) => match sleep.poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(()) => this.state.set(State::Idle),
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+use std::cell::Cell;
+
+#[cfg(test)]
+use super::backoff::{BackoffBuilder, ConstantBackoffBuilder, ExponentialBackoffBuilder};
+
+#[tokio::test]
+async fn test_retry_transient_then_success() {
+    let calls = Cell::new(0);
+    let mut backoff = ConstantBackoffBuilder::default()
+        .delay(Duration::from_millis(1))
+        .build()
+        .unwrap();
+
+    let result = (|| {
+        calls.set(calls.get() + 1);
+        let n = calls.get();
+        async move {
+            if n < 3 {
+                Err(RetryError::Transient("not yet"))
+            } else {
+                Ok(n)
+            }
+        }
+    })
+    .retry(&mut backoff)
+    .await;
+
+    assert_eq!(Ok(3), result);
+    assert_eq!(3, calls.get());
+
+    // After the reset() on success the backoff still hands out its fixed delay.
+    assert_eq!(Duration::from_millis(1), backoff.wait());
+}
+
+#[tokio::test]
+async fn test_retry_permanent_returns_immediately() {
+    let calls = Cell::new(0);
+    // A delay this long would hang the test if it were ever consulted.
+    let mut backoff = ConstantBackoffBuilder::default()
+        .delay(Duration::from_secs(60))
+        .build()
+        .unwrap();
+
+    let result: Result<(), &str> = (|| {
+        calls.set(calls.get() + 1);
+        async move { Err(RetryError::Permanent("nope")) }
+    })
+    .retry(&mut backoff)
+    .await;
+
+    assert_eq!(Err("nope"), result);
+    assert_eq!(1, calls.get(), "a permanent error should not be retried");
+}
+
+#[tokio::test]
+async fn test_retry_exhausts_max_times() {
+    let mut backoff = ConstantBackoffBuilder::default()
+        .delay(Duration::from_millis(1))
+        .max_times(2)
+        .build()
+        .unwrap();
+
+    let result: Result<(), &str> = (|| async move { Err(RetryError::Transient("still failing")) })
+        .retry(&mut backoff)
+        .await;
+
+    assert_eq!(Err("still failing"), result);
+}
+
+#[tokio::test]
+async fn test_retry_exhausts_max_elapsed_time() {
+    let mut backoff = ExponentialBackoffBuilder::default()
+        .min(Duration::from_millis(0))
+        .max(Duration::from_millis(1))
+        .factor(2.0)
+        .max_elapsed_time(Duration::from_millis(5))
+        .build()
+        .unwrap();
+
+    let result: Result<(), &str> = (|| async move { Err(RetryError::Transient("still failing")) })
+        .retry(&mut backoff)
+        .await;
+
+    assert_eq!(Err("still failing"), result);
+}
+
+#[tokio::test]
+async fn test_retry_adaptive_transient_then_success_decays_instead_of_resetting() {
+    let calls = Cell::new(0);
+    let build = || {
+        ExponentialBackoffBuilder::default()
+            .min(Duration::from_millis(1))
+            .max(Duration::from_millis(50))
+            .factor(2.0)
+            .adaptive()
+            .build()
+            .unwrap()
+    };
+    let mut tracked = build();
+    let mut fresh = build();
+
+    let result = (|| {
+        calls.set(calls.get() + 1);
+        let n = calls.get();
+        async move {
+            if n < 2 {
+                Err(RetryError::Transient("not yet"))
+            } else {
+                Ok(n)
+            }
+        }
+    })
+    .retry_adaptive(&mut tracked)
+    .await;
+    assert_eq!(Ok(2), result);
+
+    // If `.retry_adaptive()` had called the generic `Backoff::reset()` on
+    // success instead of `Adaptable::success()`, `tracked`'s fail factor
+    // would be back to its initial value and its delay back to the base
+    // delay, so this `fail()` would return at least as much as a brand-new
+    // backoff's first failure.
+    assert!(
+        tracked.fail() < fresh.fail(),
+        "success() should leave the fail factor accumulated from the earlier failure"
+    );
+}
+
+#[tokio::test]
+async fn test_retry_adaptive_exhausts_max_times() {
+    let mut backoff = ExponentialBackoffBuilder::default()
+        .min(Duration::from_millis(1))
+        .max(Duration::from_millis(5))
+        .factor(2.0)
+        .adaptive()
+        .max_times(2)
+        .build()
+        .unwrap();
+
+    let result: Result<(), &str> = (|| async move { Err(RetryError::Transient("still failing")) })
+        .retry_adaptive(&mut backoff)
+        .await;
+
+    assert_eq!(Err("still failing"), result);
+}
+
+#[tokio::test]
+async fn test_retry_adaptive_exhausts_max_elapsed_time() {
+    let mut backoff = ExponentialBackoffBuilder::default()
+        .min(Duration::from_millis(0))
+        .max(Duration::from_millis(1))
+        .factor(2.0)
+        .adaptive()
+        .max_elapsed_time(Duration::from_millis(5))
+        .build()
+        .unwrap();
+
+    let result: Result<(), &str> = (|| async move { Err(RetryError::Transient("still failing")) })
+        .retry_adaptive(&mut backoff)
+        .await;
+
+    assert_eq!(Err("still failing"), result);
+}